@@ -125,6 +125,144 @@ pub fn find_patterns(text: &str, patterns: Vec<String>) -> HashMap<String, Vec<u
     matches
 }
 
+/// A Levenshtein automaton: tracks the edit-distance row between a fixed
+/// pattern and whatever text has been fed into it so far via [`Self::step`].
+struct LevenshteinAutomaton {
+    pattern: Vec<char>,
+    max_distance: usize,
+    is_prefix: bool,
+}
+
+impl LevenshteinAutomaton {
+    fn new(pattern: &str, max_distance: usize, is_prefix: bool) -> Self {
+        LevenshteinAutomaton {
+            pattern: pattern.chars().collect(),
+            max_distance,
+            is_prefix,
+        }
+    }
+
+    /// The initial row: distance `i` to match the first `i` pattern characters against nothing.
+    fn start_state(&self) -> Vec<usize> {
+        (0..=self.pattern.len()).collect()
+    }
+
+    /// Feed one more text character, producing the next row of the DP table.
+    fn step(&self, state: &[usize], c: char) -> Vec<usize> {
+        let m = self.pattern.len();
+        let mut next = vec![0usize; m + 1];
+        next[0] = state[0] + 1;
+        for j in 0..m {
+            let cost = if self.pattern[j] == c { 0 } else { 1 };
+            let mut value = (state[j] + cost)
+                .min(state[j + 1] + 1)
+                .min(next[j] + 1);
+            if self.is_prefix && j + 1 == m {
+                // Once the whole pattern has been consumed, stop charging
+                // for extra trailing text so prefix matches aren't penalized.
+                value = value.min(state[j + 1]);
+            }
+            next[j + 1] = value;
+        }
+        next
+    }
+
+    /// The edit distance between the pattern and all text fed in so far. In
+    /// prefix mode this is instead the distance to the closest-matching
+    /// prefix of that text, since trailing characters are not charged.
+    fn distance(&self, state: &[usize]) -> usize {
+        state[self.pattern.len()]
+    }
+}
+
+/// Fuzzy pattern matching using Levenshtein automata, allowing up to `max_distance` edits.
+///
+/// Each pattern gets its own automaton. Within every word-token window of
+/// `text`, the automaton is fed forward from each starting character,
+/// recording every span whose edit distance to the pattern is within
+/// `max_distance`. Overlapping spans (within or across patterns) are then
+/// resolved by preferring the longest matched text, matching typical
+/// search-highlight semantics.
+#[pyo3::pyfunction]
+pub fn find_patterns_fuzzy(
+    text: &str,
+    patterns: Vec<String>,
+    max_distance: usize,
+    prefix: bool,
+) -> HashMap<String, Vec<(usize, usize, usize)>> {
+    let automata: Vec<LevenshteinAutomaton> = patterns
+        .iter()
+        .map(|p| LevenshteinAutomaton::new(p, max_distance, prefix))
+        .collect();
+
+    struct Candidate {
+        pattern_idx: usize,
+        start: usize,
+        end: usize,
+        distance: usize,
+    }
+
+    let mut candidates = Vec::new();
+    for (tok_start, word) in text.unicode_word_indices() {
+        let chars: Vec<char> = word.chars().collect();
+        let mut byte_offsets = Vec::with_capacity(chars.len() + 1);
+        let mut offset = tok_start;
+        for &c in &chars {
+            byte_offsets.push(offset);
+            offset += c.len_utf8();
+        }
+        byte_offsets.push(offset);
+
+        for (pattern_idx, automaton) in automata.iter().enumerate() {
+            // A non-prefix match can't need more characters than the pattern
+            // plus the allowed edits, so bound how far forward each start
+            // position scans. Prefix matches can legitimately extend past
+            // that (trailing characters are free once the pattern is fully
+            // consumed, see `step`), so scan all the way to the end of the
+            // word token instead.
+            let window = if automaton.is_prefix {
+                chars.len()
+            } else {
+                automaton.pattern.len() + max_distance
+            };
+            for i in 0..chars.len() {
+                let mut state = automaton.start_state();
+                for j in i..chars.len().min(i + window) {
+                    state = automaton.step(&state, chars[j]);
+                    let distance = automaton.distance(&state);
+                    if distance <= max_distance {
+                        candidates.push(Candidate {
+                            pattern_idx,
+                            start: byte_offsets[i],
+                            end: byte_offsets[j + 1],
+                            distance,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    // Longest matched text wins when spans overlap.
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.end - c.start));
+
+    let mut occupied: Vec<(usize, usize)> = Vec::new();
+    let mut matches: HashMap<String, Vec<(usize, usize, usize)>> = HashMap::new();
+    for candidate in candidates {
+        let overlaps = occupied.iter().any(|&(s, e)| candidate.start < e && s < candidate.end);
+        if overlaps {
+            continue;
+        }
+        occupied.push((candidate.start, candidate.end));
+        matches
+            .entry(patterns[candidate.pattern_idx].clone())
+            .or_insert_with(Vec::new)
+            .push((candidate.start, candidate.end, candidate.distance));
+    }
+
+    matches
+}
+
 /// Parallel text processing for large datasets
 #[pyo3::pyfunction]
 pub fn process_text_batch(texts: Vec<String>) -> Vec<StringStats> {
@@ -144,6 +282,344 @@ pub fn tokenize(text: &str, delimiters: &str) -> Vec<String> {
         .collect()
 }
 
+/// A token produced by [`segment_words`], carrying its byte span in the source text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentedToken {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Dictionary-based word segmentation for space-free scripts (jieba-style).
+///
+/// Builds a DAG of every dictionary word starting at each character position,
+/// then runs a backward max-probability DP over that DAG to pick the most
+/// likely segmentation. Characters not covered by any dictionary entry form
+/// maximal unknown runs; when HMM tables are supplied these runs are handed
+/// to [`hmm_segment`] instead of falling back to single-character tokens.
+#[pyo3::pyfunction]
+#[pyo3(signature = (text, dict, hmm_init=None, hmm_trans=None, hmm_emit=None))]
+pub fn segment_words(
+    text: &str,
+    dict: HashMap<String, u64>,
+    hmm_init: Option<[f64; 4]>,
+    hmm_trans: Option<[[f64; 4]; 4]>,
+    hmm_emit: Option<HashMap<char, [f64; 4]>>,
+) -> Vec<SegmentedToken> {
+    let total: u64 = dict.values().sum();
+    let chars: Vec<char> = text.chars().collect();
+    let n = chars.len();
+
+    let mut byte_offsets = Vec::with_capacity(n + 1);
+    let mut offset = 0;
+    for &c in &chars {
+        byte_offsets.push(offset);
+        offset += c.len_utf8();
+    }
+    byte_offsets.push(offset);
+
+    // dag[i] holds every end index j such that chars[i..=j] is a dictionary word.
+    // No dictionary word is longer than `max_word_len` characters, so that
+    // bounds how far forward each start position needs to scan.
+    let max_word_len = dict.keys().map(|w| w.chars().count()).max().unwrap_or(0);
+    let mut dag: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for i in 0..n {
+        let mut word = String::new();
+        for j in i..n.min(i + max_word_len) {
+            word.push(chars[j]);
+            if dict.contains_key(&word) {
+                dag[i].push(j);
+            }
+        }
+    }
+
+    // route[i] = (best log-probability from i to the end, index of the next token).
+    let mut route: Vec<(f64, usize)> = vec![(0.0, n); n + 1];
+    for i in (0..n).rev() {
+        let edges: &[usize] = if dag[i].is_empty() { std::slice::from_ref(&i) } else { &dag[i] };
+        let mut best = (f64::NEG_INFINITY, i + 1);
+        for &j in edges {
+            let word: String = chars[i..=j].iter().collect();
+            let freq = dict.get(&word).copied().unwrap_or(1).max(1);
+            let score = (freq as f64 / total.max(1) as f64).ln() + route[j + 1].0;
+            if score > best.0 {
+                best = (score, j + 1);
+            }
+        }
+        route[i] = best;
+    }
+
+    // Walk the route, tracking which single-character tokens came from an
+    // uncovered DAG position (`is_unknown`) rather than an actual dictionary hit.
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let next = route[i].1;
+        let is_unknown = next == i + 1 && dag[i].is_empty();
+        spans.push((i, next, is_unknown));
+        i = next;
+    }
+
+    let hmm_tables = match (hmm_init, hmm_trans, hmm_emit) {
+        (Some(init), Some(trans), Some(emit)) => Some((init, trans, emit)),
+        _ => None,
+    };
+
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+    while idx < spans.len() {
+        if !spans[idx].2 {
+            let (start, end, _) = spans[idx];
+            tokens.push(SegmentedToken {
+                text: chars[start..end].iter().collect(),
+                start: byte_offsets[start],
+                end: byte_offsets[end],
+            });
+            idx += 1;
+            continue;
+        }
+
+        // Gather the maximal run of consecutive unknown single characters.
+        let run_start_idx = idx;
+        while idx < spans.len() && spans[idx].2 {
+            idx += 1;
+        }
+        let char_start = spans[run_start_idx].0;
+        let char_end = spans[idx - 1].1;
+        let run_text: String = chars[char_start..char_end].iter().collect();
+
+        if let Some((init, trans, emit)) = &hmm_tables {
+            let mut pos = byte_offsets[char_start];
+            for word in hmm_segment(&run_text, *init, *trans, emit.clone()) {
+                let len = word.len();
+                tokens.push(SegmentedToken { text: word, start: pos, end: pos + len });
+                pos += len;
+            }
+        } else {
+            for c in char_start..char_end {
+                tokens.push(SegmentedToken {
+                    text: chars[c].to_string(),
+                    start: byte_offsets[c],
+                    end: byte_offsets[c + 1],
+                });
+            }
+        }
+    }
+    tokens
+}
+
+/// The four BMES tagging states used by [`hmm_segment`]: Begin, Middle, End, Single.
+const BMES_STATES: [&str; 4] = ["B", "M", "E", "S"];
+
+/// HMM/Viterbi segmenter for contiguous runs of unknown characters.
+///
+/// Each character is tagged with one of the four BMES states via a Viterbi
+/// search over caller-supplied log-probability tables, then words are cut at
+/// `E` (end of word) and `S` (single-character word) boundaries. Unseen
+/// characters fall back to a small emission floor rather than failing.
+#[pyo3::pyfunction]
+pub fn hmm_segment(
+    text: &str,
+    init: [f64; 4],
+    trans: [[f64; 4]; 4],
+    emit: HashMap<char, [f64; 4]>,
+) -> Vec<String> {
+    const EMIT_FLOOR: f64 = -20.0;
+
+    let chars: Vec<char> = text.chars().collect();
+    let n = chars.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let emit_prob = |c: char, state: usize| emit.get(&c).map_or(EMIT_FLOOR, |probs| probs[state]);
+
+    let mut scores = vec![[f64::NEG_INFINITY; 4]; n];
+    let mut backptrs = vec![[0usize; 4]; n];
+
+    for state in 0..4 {
+        scores[0][state] = init[state] + emit_prob(chars[0], state);
+    }
+
+    for t in 1..n {
+        for state in 0..4 {
+            let mut best = (f64::NEG_INFINITY, 0usize);
+            for prev in 0..4 {
+                let score = scores[t - 1][prev] + trans[prev][state];
+                if score > best.0 {
+                    best = (score, prev);
+                }
+            }
+            scores[t][state] = best.0 + emit_prob(chars[t], state);
+            backptrs[t][state] = best.1;
+        }
+    }
+
+    let mut best_state = 0;
+    for state in 1..4 {
+        if scores[n - 1][state] > scores[n - 1][best_state] {
+            best_state = state;
+        }
+    }
+
+    let mut path = vec![0usize; n];
+    path[n - 1] = best_state;
+    for t in (1..n).rev() {
+        path[t - 1] = backptrs[t][path[t]];
+    }
+
+    let mut words = Vec::new();
+    let mut start = 0;
+    for t in 0..n {
+        if matches!(BMES_STATES[path[t]], "E" | "S") {
+            words.push(chars[start..=t].iter().collect());
+            start = t + 1;
+        }
+    }
+    if start < n {
+        words.push(chars[start..n].iter().collect());
+    }
+    words
+}
+
+/// Every string reachable from `word` by deleting up to `max_distance`
+/// `char`s, including `word` itself. Used to build and query the
+/// symmetric-delete index behind [`SpellChecker`]. Deletes are generated
+/// over `chars()` rather than grapheme clusters so the index stays
+/// consistent with [`levenshtein_distance`], which is also `char`-based;
+/// mixing the two metrics would let the pruning silently drop true matches
+/// containing multi-codepoint clusters (e.g. NFD-decomposed accents).
+fn generate_deletes(word: &str, max_distance: usize) -> std::collections::HashSet<String> {
+    let mut seen = std::collections::HashSet::new();
+    seen.insert(word.to_string());
+
+    let mut frontier = vec![word.to_string()];
+    for _ in 0..max_distance {
+        let mut next_frontier = Vec::new();
+        for w in &frontier {
+            let chars: Vec<char> = w.chars().collect();
+            for i in 0..chars.len() {
+                let variant: String = chars[..i].iter().chain(&chars[i + 1..]).collect();
+                if seen.insert(variant.clone()) {
+                    next_frontier.push(variant);
+                }
+            }
+        }
+        if next_frontier.is_empty() {
+            break;
+        }
+        frontier = next_frontier;
+    }
+
+    seen
+}
+
+/// Symmetric-delete spell checker over a word-frequency dictionary.
+///
+/// Rather than scoring every dictionary entry against each query (as
+/// [`levenshtein_distance`] alone would require), [`Self::load_dictionary`]
+/// indexes every dictionary word under each of its own deletion variants up
+/// to `max_edit_distance`. A query then only needs to generate its own
+/// deletion variants and look them up, narrowing candidates to a handful
+/// before the exact edit distance is computed.
+#[pyo3::pyclass]
+pub struct SpellChecker {
+    frequencies: HashMap<String, u64>,
+    delete_index: HashMap<String, Vec<String>>,
+    max_edit_distance: usize,
+}
+
+#[pyo3::pymethods]
+impl SpellChecker {
+    #[new]
+    #[pyo3(signature = (max_edit_distance=2))]
+    fn new(max_edit_distance: usize) -> Self {
+        SpellChecker {
+            frequencies: HashMap::new(),
+            delete_index: HashMap::new(),
+            max_edit_distance,
+        }
+    }
+
+    /// Load a dictionary, replacing any previously loaded one. `words` may be
+    /// a plain word list (each entry gets frequency 1) or paired with
+    /// `frequencies` of the same length.
+    #[pyo3(text_signature = "($self, words, frequencies=None)")]
+    #[pyo3(signature = (words, frequencies=None))]
+    fn load_dictionary(&mut self, words: Vec<String>, frequencies: Option<Vec<u64>>) -> PyResult<()> {
+        if let Some(freqs) = &frequencies {
+            if freqs.len() != words.len() {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "words and frequencies must be the same length",
+                ));
+            }
+        }
+
+        self.frequencies.clear();
+        self.delete_index.clear();
+
+        for (i, word) in words.into_iter().enumerate() {
+            let frequency = frequencies.as_ref().map_or(1, |f| f[i]);
+            for variant in generate_deletes(&word, self.max_edit_distance) {
+                self.delete_index.entry(variant).or_insert_with(Vec::new).push(word.clone());
+            }
+            self.frequencies.insert(word, frequency);
+        }
+
+        Ok(())
+    }
+
+    /// Whether `word` is present in the loaded dictionary verbatim.
+    fn check(&self, word: &str) -> bool {
+        self.frequencies.contains_key(word)
+    }
+
+    /// Check a batch of words in parallel, mirroring [`process_text_batch`].
+    fn check_batch(&self, words: Vec<String>) -> Vec<bool> {
+        words.par_iter().map(|word| self.check(word)).collect()
+    }
+
+    /// Suggest up to `limit` corrections for `word` within `max_distance`
+    /// edits, ranked by `(distance asc, frequency desc)`. `max_distance` is
+    /// clamped to the index's configured `max_edit_distance`.
+    #[pyo3(text_signature = "($self, word, max_distance, limit)")]
+    fn suggest(&self, word: &str, max_distance: usize, limit: usize) -> Vec<(String, usize, u64)> {
+        let max_distance = max_distance.min(self.max_edit_distance);
+
+        let mut candidates: std::collections::HashSet<&str> = std::collections::HashSet::new();
+        for variant in generate_deletes(word, max_distance) {
+            if let Some(words) = self.delete_index.get(&variant) {
+                candidates.extend(words.iter().map(String::as_str));
+            }
+        }
+
+        let mut suggestions: Vec<(String, usize, u64)> = candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                let distance = levenshtein_distance(word, candidate);
+                (distance <= max_distance)
+                    .then(|| (candidate.to_string(), distance, self.frequencies[candidate]))
+            })
+            .collect();
+
+        suggestions.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| b.2.cmp(&a.2)));
+        suggestions.truncate(limit);
+        suggestions
+    }
+
+    /// Suggest corrections for a batch of words in parallel, mirroring
+    /// [`process_text_batch`].
+    #[pyo3(text_signature = "($self, words, max_distance, limit)")]
+    fn suggest_batch(
+        &self,
+        words: Vec<String>,
+        max_distance: usize,
+        limit: usize,
+    ) -> Vec<Vec<(String, usize, u64)>> {
+        words.par_iter().map(|word| self.suggest(word, max_distance, limit)).collect()
+    }
+}
+
 /// Initialize the Python module
 #[pymodule]
 fn lexicaforge_rust(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -151,7 +627,14 @@ fn lexicaforge_rust(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(levenshtein_distance, m)?)?;
     m.add_function(wrap_pyfunction!(normalize_string, m)?)?;
     m.add_function(wrap_pyfunction!(find_patterns, m)?)?;
+    m.add_function(wrap_pyfunction!(find_patterns_fuzzy, m)?)?;
     m.add_function(wrap_pyfunction!(process_text_batch, m)?)?;
     m.add_function(wrap_pyfunction!(tokenize, m)?)?;
+    m.add_function(wrap_pyfunction!(segment_words, m)?)?;
+    m.add_function(wrap_pyfunction!(hmm_segment, m)?)?;
+    m.add_class::<SpellChecker>()?;
     Ok(())
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
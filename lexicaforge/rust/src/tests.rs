@@ -66,6 +66,50 @@ mod tests {
         assert_eq!(matches, expected);
     }
 
+    #[test]
+    fn test_find_patterns_fuzzy() {
+        let text = "The quikc brown fox jumps over the lazy dog";
+        let patterns = vec!["quick".to_string(), "fox".to_string()];
+
+        let matches = find_patterns_fuzzy(text, patterns, 2, false);
+
+        assert_eq!(matches["quick"], vec![(4, 9, 2)]);
+
+        // At distance 2, "fox" also fuzzy-matches several incidental,
+        // disjoint spans ("row" in brown, "ov" in over, the whole word
+        // "dog"), so none of them get dropped by overlap resolution.
+        let mut fox_matches = matches["fox"].clone();
+        fox_matches.sort();
+        assert_eq!(
+            fox_matches,
+            vec![(11, 14, 2), (16, 19, 0), (26, 28, 2), (40, 43, 2)]
+        );
+    }
+
+    #[test]
+    fn test_find_patterns_fuzzy_overlap_prefers_longest() {
+        let text = "reading";
+        let patterns = vec!["read".to_string(), "reading".to_string()];
+
+        let matches = find_patterns_fuzzy(text, patterns, 0, false);
+
+        assert!(matches.get("read").is_none());
+        assert_eq!(matches["reading"], vec![(0, 7, 0)]);
+    }
+
+    #[test]
+    fn test_find_patterns_fuzzy_prefix_extends_past_window() {
+        // "reading" is longer than pattern.len() + max_distance (4 + 1 = 5),
+        // so a correct prefix match must still extend to the end of the
+        // word instead of truncating at the window bound.
+        let text = "reading quickly";
+        let patterns = vec!["read".to_string()];
+
+        let matches = find_patterns_fuzzy(text, patterns, 1, true);
+
+        assert_eq!(matches["read"], vec![(0, 7, 0)]);
+    }
+
     #[test]
     fn test_process_text_batch() {
         let texts = vec![
@@ -105,10 +149,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_segment_words() {
+        let mut dict = HashMap::new();
+        dict.insert("我们".to_string(), 10);
+        dict.insert("我".to_string(), 5);
+        dict.insert("们".to_string(), 3);
+        dict.insert("在".to_string(), 8);
+        dict.insert("北京".to_string(), 6);
+
+        let tokens = segment_words("我们在北京", dict);
+        let words: Vec<String> = tokens.iter().map(|t| t.text.clone()).collect();
+
+        assert_eq!(words, vec!["我们", "在", "北京"]);
+        assert_eq!(tokens[0].start, 0);
+        assert_eq!(tokens[0].end, "我们".len());
+        assert_eq!(tokens[2].end, "我们在北京".len());
+    }
+
+    #[test]
+    fn test_segment_words_large_input_bounded_by_max_word_len() {
+        // The DAG build must scan at most `max_word_len` characters ahead
+        // of each start position rather than to the end of the text, or
+        // this repeats-to-thousands-of-characters input becomes
+        // prohibitively slow (and was, before that bound was added).
+        let mut dict = HashMap::new();
+        dict.insert("我们".to_string(), 10);
+        dict.insert("在".to_string(), 8);
+        dict.insert("北京".to_string(), 6);
+
+        let text = "我们在北京".repeat(1000);
+        let tokens = segment_words(&text, dict);
+        let words: Vec<String> = tokens.iter().map(|t| t.text.clone()).collect();
+
+        assert_eq!(words.len(), 3 * 1000);
+        assert_eq!(words[0..3], ["我们".to_string(), "在".to_string(), "北京".to_string()]);
+    }
+
+    #[test]
+    fn test_segment_words_unknown_chars() {
+        let mut dict = HashMap::new();
+        dict.insert("hello".to_string(), 5);
+
+        let tokens = segment_words("hello??", dict);
+        let words: Vec<String> = tokens.iter().map(|t| t.text.clone()).collect();
+
+        assert_eq!(words, vec!["hello", "?", "?"]);
+    }
+
+    #[test]
+    fn test_hmm_segment() {
+        // States are ordered [B, M, E, S].
+        let init = [-0.5, -3.0, -3.0, -1.0];
+        let trans = [
+            [-3.0, -0.5, -0.5, -3.0], // from B
+            [-3.0, -0.5, -0.5, -3.0], // from M
+            [-0.5, -3.0, -3.0, -0.5], // from E
+            [-0.5, -3.0, -3.0, -0.5], // from S
+        ];
+        let mut emit = HashMap::new();
+        emit.insert('猫', [-0.3, -3.0, -3.0, -1.5]); // strongly Begin
+        emit.insert('小', [-3.0, -3.0, -0.3, -3.0]); // strongly End
+        emit.insert('王', [-1.5, -3.0, -3.0, -0.3]); // strongly Single
+
+        let words = hmm_segment("猫小王", init, trans, emit);
+
+        assert_eq!(words, vec!["猫小".to_string(), "王".to_string()]);
+    }
+
+    #[test]
+    fn test_segment_words_hmm_fallback_on_unknown_run() {
+        let mut dict = HashMap::new();
+        dict.insert("在".to_string(), 8);
+
+        let init = [-0.5, -3.0, -3.0, -1.0];
+        let trans = [
+            [-3.0, -0.5, -0.5, -3.0],
+            [-3.0, -0.5, -0.5, -3.0],
+            [-0.5, -3.0, -3.0, -0.5],
+            [-0.5, -3.0, -3.0, -0.5],
+        ];
+        let mut emit = HashMap::new();
+        emit.insert('猫', [-0.3, -3.0, -3.0, -1.5]);
+        emit.insert('小', [-3.0, -3.0, -0.3, -3.0]);
+        emit.insert('王', [-1.5, -3.0, -3.0, -0.3]);
+
+        let tokens = segment_words("猫小在王", dict, Some(init), Some(trans), Some(emit));
+        let words: Vec<String> = tokens.iter().map(|t| t.text.clone()).collect();
+
+        assert_eq!(words, vec!["猫小".to_string(), "在".to_string(), "王".to_string()]);
+    }
+
     #[test]
     fn test_error_handling() {
         // Test invalid UTF-8 handling
         let invalid_text = unsafe { std::str::from_utf8_unchecked(&[0xFF, 0xFF]) };
         assert!(calculate_string_stats(invalid_text).is_err());
     }
+
+    #[test]
+    fn test_spell_checker_check() {
+        let mut checker = SpellChecker::new(2);
+        checker
+            .load_dictionary(vec!["hello".to_string(), "world".to_string()], None)
+            .unwrap();
+
+        assert!(checker.check("hello"));
+        assert!(!checker.check("helo"));
+    }
+
+    #[test]
+    fn test_spell_checker_suggest() {
+        let mut checker = SpellChecker::new(2);
+        checker
+            .load_dictionary(
+                vec!["hello".to_string(), "help".to_string(), "world".to_string()],
+                Some(vec![10, 5, 8]),
+            )
+            .unwrap();
+
+        let suggestions = checker.suggest("helo", 2, 5);
+        let candidates: Vec<&str> = suggestions.iter().map(|(word, _, _)| word.as_str()).collect();
+
+        assert!(candidates.contains(&"hello"));
+        assert!(candidates.contains(&"help"));
+        assert!(!candidates.contains(&"world"));
+    }
+
+    #[test]
+    fn test_spell_checker_suggest_ranking_and_limit() {
+        let mut checker = SpellChecker::new(2);
+        checker
+            .load_dictionary(
+                vec!["cat".to_string(), "cot".to_string(), "car".to_string()],
+                Some(vec![1, 100, 50]),
+            )
+            .unwrap();
+
+        let suggestions = checker.suggest("cat", 1, 2);
+
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0], ("cat".to_string(), 0, 1));
+        assert_eq!(suggestions[1].0, "cot".to_string());
+    }
+
+    #[test]
+    fn test_spell_checker_suggest_multi_codepoint_grapheme() {
+        // "a\u{0301}\u{0300}bc" is a single grapheme cluster (a plus two
+        // combining marks) followed by "bc". Its char-level Levenshtein
+        // distance from "a\u{0301}bc" (one mark dropped) is 1, so the
+        // delete index must be built over chars, not grapheme clusters, or
+        // this candidate is unreachable within `max_distance`.
+        let mut checker = SpellChecker::new(1);
+        let dict_word = "a\u{0301}\u{0300}bc".to_string();
+        checker
+            .load_dictionary(vec![dict_word.clone()], None)
+            .unwrap();
+
+        let suggestions = checker.suggest("a\u{0301}bc", 1, 5);
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].0, dict_word);
+        assert_eq!(suggestions[0].1, 1);
+    }
 } 
\ No newline at end of file
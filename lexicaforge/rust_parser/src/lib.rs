@@ -6,6 +6,7 @@ use thiserror::Error;
 use rayon::prelude::*;
 use lazy_static::lazy_static;
 use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Custom error type for parser operations
 #[derive(Error, Debug)]
@@ -25,6 +26,16 @@ struct Morpheme {
     length: usize,
 }
 
+/// One grapheme of a syllable mapped onto a positional letter role (see
+/// [`RustParser::classify_syllable`]).
+#[derive(Debug, Serialize, Deserialize)]
+struct SyllableLetter {
+    text: String,
+    role: String,
+    position: usize,
+    length: usize,
+}
+
 /// Represents a parsed word with its morphological analysis
 #[derive(Debug, Serialize, Deserialize)]
 struct ParsedWord {
@@ -32,13 +43,54 @@ struct ParsedWord {
     normalized: String,
     morphemes: Vec<Morpheme>,
     confidence: f64,
+    syllables: Option<Vec<SyllableLetter>>,
 }
 
+/// Canonical slot order for syllable-level letter classification. Stacked
+/// scripts such as Tibetan fill these left to right, though any individual
+/// syllable may skip slots it has no letter for.
+const SYLLABLE_ROLES: [&str; 6] = [
+    "Prefix",
+    "Superscript",
+    "Root",
+    "Subjoined",
+    "Vowel",
+    "Suffix",
+];
+
 /// Python class for the high-performance parser
 #[pyclass]
 struct RustParser {
     prefix_patterns: HashMap<String, String>,
     suffix_patterns: HashMap<String, String>,
+    syllable_alphabet: HashMap<String, Vec<String>>,
+}
+
+/// Find the longest key in `patterns` that exactly matches the graphemes at
+/// the start (`from_start`) or end of `graphemes[range]`, returning it
+/// alongside its length in graphemes.
+fn longest_match<'a>(
+    patterns: &'a HashMap<String, String>,
+    graphemes: &[&str],
+    range: std::ops::Range<usize>,
+    from_start: bool,
+) -> Option<(&'a str, usize)> {
+    let span = range.end - range.start;
+    patterns
+        .keys()
+        .filter_map(|pattern| {
+            let plen = pattern.graphemes(true).count();
+            if plen == 0 || plen > span {
+                return None;
+            }
+            let window = if from_start {
+                &graphemes[range.start..range.start + plen]
+            } else {
+                &graphemes[range.end - plen..range.end]
+            };
+            (window.concat() == *pattern).then_some((pattern.as_str(), plen))
+        })
+        .max_by_key(|&(_, plen)| plen)
 }
 
 #[pymethods]
@@ -48,6 +100,7 @@ impl RustParser {
         Ok(RustParser {
             prefix_patterns: HashMap::new(),
             suffix_patterns: HashMap::new(),
+            syllable_alphabet: HashMap::new(),
         })
     }
 
@@ -72,11 +125,28 @@ impl RustParser {
         Ok(())
     }
 
+    /// Initialize the syllable letter-role alphabet from a Python dict
+    /// mapping each grapheme to the ordered list of roles it may fill, e.g.
+    /// `{"ར": ["Prefix", "Suffix"], "ི": ["Vowel"]}`. Supplying this enables
+    /// the syllable layer exposed through [`Self::batch_process`].
+    #[pyo3(text_signature = "($self, alphabet)")]
+    fn initialize_syllable_alphabet(&mut self, alphabet: &PyDict) -> PyResult<()> {
+        self.syllable_alphabet.clear();
+
+        for (key, value) in alphabet.iter() {
+            let grapheme: String = key.extract()?;
+            let roles: Vec<String> = value.extract()?;
+            self.syllable_alphabet.insert(grapheme, roles);
+        }
+
+        Ok(())
+    }
+
     /// Batch process a list of words in parallel
     #[pyo3(text_signature = "($self, words)")]
     fn batch_process<'py>(&self, py: Python<'py>, words: &PyList) -> PyResult<&'py PyList> {
         let words_vec: Vec<String> = words.extract()?;
-        
+
         // Process words in parallel using rayon
         let results: Vec<ParsedWord> = words_vec.par_iter()
             .map(|word| self.process_single_word(word))
@@ -89,7 +159,7 @@ impl RustParser {
             dict.set_item("original", result.original)?;
             dict.set_item("normalized", result.normalized)?;
             dict.set_item("confidence", result.confidence)?;
-            
+
             let morphemes = PyList::empty(py);
             for m in result.morphemes {
                 let m_dict = PyDict::new(py);
@@ -100,75 +170,149 @@ impl RustParser {
                 morphemes.append(m_dict)?;
             }
             dict.set_item("morphemes", morphemes)?;
-            
+
+            if let Some(syllables) = result.syllables {
+                let syllables_list = PyList::empty(py);
+                for s in syllables {
+                    let s_dict = PyDict::new(py);
+                    s_dict.set_item("text", s.text)?;
+                    s_dict.set_item("role", s.role)?;
+                    s_dict.set_item("position", s.position)?;
+                    s_dict.set_item("length", s.length)?;
+                    syllables_list.append(s_dict)?;
+                }
+                dict.set_item("syllables", syllables_list)?;
+            }
+
             py_results.append(dict)?;
         }
 
         Ok(py_results)
     }
 
-    /// Process a single word with optimized Rust implementation
+    /// Process a single word with optimized Rust implementation.
+    ///
+    /// Repeatedly strips the longest matching prefix, then the longest
+    /// matching suffix, recording each as a [`Morpheme`] and discounting
+    /// confidence per stripped layer, until neither pattern set matches what
+    /// remains. The leftover span becomes the root. Operates on
+    /// [`unicode_segmentation`] graphemes rather than byte offsets so
+    /// multi-byte clusters are never split mid-character.
     fn process_single_word(&self, word: &str) -> ParsedWord {
         let normalized = word.to_lowercase();
         let mut morphemes = Vec::new();
         let mut confidence = 1.0;
 
-        // Find prefixes
-        for (pattern, meaning) in &self.prefix_patterns {
-            if let Some(pos) = normalized.find(pattern) {
-                if pos == 0 {  // Only consider prefix at start
-                    morphemes.push(Morpheme {
-                        text: pattern.clone(),
-                        morpheme_type: "prefix".to_string(),
-                        position: 0,
-                        length: pattern.len(),
-                    });
-                    confidence *= 0.9;  // Adjust confidence
-                    break;
-                }
-            }
+        let graphemes: Vec<&str> = normalized.graphemes(true).collect();
+        let mut byte_offsets = Vec::with_capacity(graphemes.len() + 1);
+        let mut offset = 0;
+        for g in &graphemes {
+            byte_offsets.push(offset);
+            offset += g.len();
         }
+        byte_offsets.push(offset);
 
-        // Find suffixes
-        for (pattern, meaning) in &self.suffix_patterns {
-            if let Some(pos) = normalized.rfind(pattern) {
-                if pos + pattern.len() == normalized.len() {  // Only consider suffix at end
-                    morphemes.push(Morpheme {
-                        text: pattern.clone(),
-                        morpheme_type: "suffix".to_string(),
-                        position: pos,
-                        length: pattern.len(),
-                    });
-                    confidence *= 0.9;  // Adjust confidence
-                    break;
-                }
+        let mut start = 0usize;
+        let mut end = graphemes.len();
+
+        loop {
+            let mut stripped = false;
+
+            if let Some((pattern, plen)) =
+                longest_match(&self.prefix_patterns, &graphemes, start..end, true)
+            {
+                morphemes.push(Morpheme {
+                    text: pattern.to_string(),
+                    morpheme_type: "prefix".to_string(),
+                    position: byte_offsets[start],
+                    length: byte_offsets[start + plen] - byte_offsets[start],
+                });
+                confidence *= 0.9;
+                start += plen;
+                stripped = true;
+            }
+
+            if let Some((pattern, plen)) =
+                longest_match(&self.suffix_patterns, &graphemes, start..end, false)
+            {
+                morphemes.push(Morpheme {
+                    text: pattern.to_string(),
+                    morpheme_type: "suffix".to_string(),
+                    position: byte_offsets[end - plen],
+                    length: byte_offsets[end] - byte_offsets[end - plen],
+                });
+                confidence *= 0.9;
+                end -= plen;
+                stripped = true;
             }
-        }
 
-        // Extract root
-        let root_start = morphemes.iter()
-            .find(|m| m.morpheme_type == "prefix")
-            .map_or(0, |m| m.position + m.length);
-            
-        let root_end = morphemes.iter()
-            .find(|m| m.morpheme_type == "suffix")
-            .map_or(normalized.len(), |m| m.position);
+            if !stripped {
+                break;
+            }
+        }
 
-        if root_start < root_end {
+        if start < end {
             morphemes.push(Morpheme {
-                text: normalized[root_start..root_end].to_string(),
+                text: graphemes[start..end].concat(),
                 morpheme_type: "root".to_string(),
-                position: root_start,
-                length: root_end - root_start,
+                position: byte_offsets[start],
+                length: byte_offsets[end] - byte_offsets[start],
             });
         }
 
+        let syllables = if self.syllable_alphabet.is_empty() {
+            None
+        } else {
+            Some(self.classify_syllable(&normalized))
+        };
+
         ParsedWord {
             original: word.to_string(),
             normalized,
             morphemes,
             confidence,
+            syllables,
+        }
+    }
+
+    /// Classify each grapheme of `word` into a positional letter role
+    /// (see [`SYLLABLE_ROLES`]) using the configured syllable alphabet.
+    /// Roles are assigned left to right: each grapheme takes the earliest
+    /// still-available role it is eligible for, so a syllable need not
+    /// populate every slot. A grapheme with no eligible role in the
+    /// remaining slots, or with no alphabet entry at all, is classified as
+    /// `Root`.
+    fn classify_syllable(&self, word: &str) -> Vec<SyllableLetter> {
+        let mut cursor = 0usize;
+        let mut offset = 0usize;
+        let mut letters = Vec::new();
+
+        for grapheme in word.graphemes(true) {
+            let role = self
+                .syllable_alphabet
+                .get(grapheme)
+                .and_then(|allowed| {
+                    SYLLABLE_ROLES[cursor..]
+                        .iter()
+                        .position(|role| allowed.iter().any(|a| a == role))
+                        .map(|i| cursor + i)
+                })
+                .map(|idx| {
+                    cursor = idx + 1;
+                    SYLLABLE_ROLES[idx]
+                })
+                .unwrap_or("Root");
+
+            letters.push(SyllableLetter {
+                text: grapheme.to_string(),
+                role: role.to_string(),
+                position: offset,
+                length: grapheme.len(),
+            });
+            offset += grapheme.len();
         }
+
+        letters
     }
 }
 
@@ -177,4 +321,7 @@ impl RustParser {
 fn lexicaforge_parser(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<RustParser>()?;
     Ok(())
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests; 
\ No newline at end of file
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser_with_patterns(prefixes: &[(&str, &str)], suffixes: &[(&str, &str)]) -> RustParser {
+        RustParser {
+            prefix_patterns: prefixes
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            suffix_patterns: suffixes
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            syllable_alphabet: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_process_single_word_plain_root() {
+        let parser = parser_with_patterns(&[("un", "not")], &[("ing", "doing")]);
+        let result = parser.process_single_word("cat");
+
+        assert_eq!(result.morphemes.len(), 1);
+        assert_eq!(result.morphemes[0].morpheme_type, "root");
+        assert_eq!(result.morphemes[0].text, "cat");
+        assert_eq!(result.confidence, 1.0);
+    }
+
+    #[test]
+    fn test_process_single_word_strips_stacked_affixes() {
+        // "ununhappinessing" -> un, un, [root: happi], ing, ness (4 stripped layers).
+        let parser = parser_with_patterns(
+            &[("un", "not")],
+            &[("ness", "state of"), ("ing", "doing")],
+        );
+        let result = parser.process_single_word("ununhappinessing");
+
+        let prefixes: Vec<&str> = result
+            .morphemes
+            .iter()
+            .filter(|m| m.morpheme_type == "prefix")
+            .map(|m| m.text.as_str())
+            .collect();
+        let suffixes: Vec<&str> = result
+            .morphemes
+            .iter()
+            .filter(|m| m.morpheme_type == "suffix")
+            .map(|m| m.text.as_str())
+            .collect();
+        let root = result
+            .morphemes
+            .iter()
+            .find(|m| m.morpheme_type == "root")
+            .unwrap();
+
+        assert_eq!(prefixes, vec!["un", "un"]);
+        assert_eq!(suffixes, vec!["ing", "ness"]);
+        assert_eq!(root.text, "happi");
+        assert!((result.confidence - 0.9f64.powi(4)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_classify_syllable_tibetan_style_with_unknown_fallback() {
+        let mut parser = parser_with_patterns(&[], &[]);
+        parser
+            .syllable_alphabet
+            .insert("s".to_string(), vec!["Prefix".to_string()]);
+        parser
+            .syllable_alphabet
+            .insert("k".to_string(), vec!["Root".to_string()]);
+        parser
+            .syllable_alphabet
+            .insert("u".to_string(), vec!["Vowel".to_string()]);
+        // "r" has no alphabet entry at all, so it must fall back to Root.
+
+        let letters = parser.classify_syllable("skur");
+        let roles: Vec<&str> = letters.iter().map(|l| l.role.as_str()).collect();
+
+        assert_eq!(roles, vec!["Prefix", "Root", "Vowel", "Root"]);
+        assert_eq!(letters[3].text, "r");
+    }
+}